@@ -13,11 +13,13 @@
 
 use camino::{Utf8Path, Utf8PathBuf};
 use color_eyre::eyre::{bail, Report, Result, WrapErr};
-use semver::VersionReq;
+use semver::{Version, VersionReq};
 use serde::Deserialize;
 use serde_with::{serde_as, DisplayFromStr};
 use std::env;
+use std::fmt;
 use std::fs;
+use std::io::IsTerminal;
 use std::str::FromStr;
 use structopt::StructOpt;
 
@@ -31,6 +33,10 @@ pub struct Args {
     #[structopt(long = "config")]
     config_path: Option<Utf8PathBuf>,
 
+    /// Disable interactive prompts and progress bars, even on a terminal.
+    #[structopt(long)]
+    no_interactive: bool,
+
     /// Subcommand to execute
     #[structopt(subcommand)]
     subcommand: Subcommand,
@@ -48,7 +54,10 @@ impl Args {
         };
         let config = Config::read_path(&config_path)?;
 
-        self.subcommand.exec(config)
+        // Interactive features are only offered on a terminal, and only when not opted out of.
+        let interactive = !self.no_interactive && std::io::stdout().is_terminal();
+
+        self.subcommand.exec(config, interactive)
     }
 }
 
@@ -62,11 +71,18 @@ pub enum Subcommand {
         /// Release to update to (default: pinned or latest)
         #[structopt(long, short)]
         version: Option<DownloadVersion>,
+
+        /// Target triple to update for (default: config override or host triple)
+        #[structopt(long)]
+        target: Option<String>,
     },
+
+    /// Roll back to the previously installed binary
+    Rollback,
 }
 
 impl Subcommand {
-    pub fn exec(self, config: Config) -> Result<()> {
+    pub fn exec(self, config: Config, interactive: bool) -> Result<()> {
         match self {
             Subcommand::ListReleases => {
                 let releases = self_update::backends::github::ReleaseList::configure()
@@ -75,6 +91,23 @@ impl Subcommand {
                     .build()?
                     .fetch()?;
 
+                // Order by the configured scheme so date-based tags sort correctly; tags that
+                // don't parse retain their original (newest-first) position at the end.
+                let order: std::collections::HashMap<String, ParsedVersion> =
+                    parse_candidates(config.scheme, &config.prefix, &releases)
+                        .into_iter()
+                        .map(|(version, tag)| (tag.to_owned(), version))
+                        .collect();
+                let mut releases = releases;
+                releases.sort_by(|a, b| {
+                    match (order.get(a.version.as_str()), order.get(b.version.as_str())) {
+                        (Some(a), Some(b)) => b.cmp(a),
+                        (Some(_), None) => std::cmp::Ordering::Less,
+                        (None, Some(_)) => std::cmp::Ordering::Greater,
+                        (None, None) => std::cmp::Ordering::Equal,
+                    }
+                });
+
                 for release in releases {
                     println!(
                         "- Name: {}\n  Version: {}\n  Date: {}",
@@ -82,8 +115,97 @@ impl Subcommand {
                     );
                 }
             }
-            Subcommand::Update { .. } => {
-                unimplemented!();
+            Subcommand::Update { version, target } => {
+                // Resolve everything that borrows `config` as a whole before moving any field out.
+                let history_dir = config.history_dir()?;
+
+                // A version passed on the command line overrides the one pinned in the config.
+                let explicit_version = version.is_some();
+                let download_version = version.unwrap_or(config.version);
+
+                // Command line beats the config override beats the compile-time host triple.
+                let target = target
+                    .or(config.target)
+                    .unwrap_or_else(|| self_update::get_target().to_owned());
+
+                let releases = self_update::backends::github::ReleaseList::configure()
+                    .repo_owner(&config.repo.owner)
+                    .repo_name(&config.repo.name)
+                    .build()?
+                    .fetch()?;
+
+                // On a terminal with neither a `--version` nor a configured pin, let the user pick
+                // interactively; otherwise honor the pinned/latest selection rule.
+                let prompt = !explicit_version
+                    && interactive
+                    && matches!(download_version, DownloadVersion::Latest);
+                let (chosen_version, chosen_tag) = if prompt {
+                    prompt_release(config.scheme, &config.prefix, &releases)?
+                } else {
+                    select_release(
+                        config.scheme,
+                        &config.prefix,
+                        &download_version,
+                        config.lts_major,
+                        &releases,
+                    )?
+                };
+
+                let release = releases
+                    .iter()
+                    .find(|release| release.version == chosen_tag)
+                    .expect("selected tag came from the release list");
+
+                // Resolve the asset to install. With a signed manifest the manifest names the
+                // artifact and that exact file is installed; otherwise match by target triple and
+                // archive suffix. Either way a missing build fails clearly before the artifact is
+                // downloaded.
+                let (asset, manifest_entry) = match &config.verification {
+                    Some(verification) => {
+                        let entry = fetch_manifest_entry(verification, &target, release)?;
+                        let asset = release
+                            .assets
+                            .iter()
+                            .find(|asset| asset.name == entry.artifact)
+                            .ok_or_else(|| {
+                                Report::msg(format!(
+                                    "release has no artifact asset '{}'",
+                                    entry.artifact
+                                ))
+                            })?;
+                        (asset, Some(entry))
+                    }
+                    None => (select_asset(release, &target)?, None),
+                };
+
+                // If the running binary is already the chosen version, there is nothing to do, and
+                // in particular nothing should be written to the rollback history.
+                let current_version = self_update::cargo_crate_version!();
+                if ParsedVersion::parse(config.scheme, current_version).as_ref()
+                    == Some(&chosen_version)
+                {
+                    println!("already up to date at version {}", chosen_version);
+                    return Ok(());
+                }
+
+                // Save the running binary so a later `rollback` can restore it.
+                save_to_history(&history_dir, current_version, config.history_retention)?;
+
+                // Download, verify the exact bytes against the signed manifest, then install from
+                // the local file — self_update's own flow would re-fetch and install bytes we never
+                // checked.
+                install_update(
+                    asset,
+                    &config.repo.name,
+                    config.verification.as_ref().zip(manifest_entry.as_ref()),
+                    interactive,
+                )?;
+
+                println!("updated to version {}", chosen_version);
+            }
+            Subcommand::Rollback => {
+                let version = rollback(&config.history_dir()?)?;
+                println!("rolled back to version {}", version);
             }
         }
 
@@ -91,6 +213,96 @@ impl Subcommand {
     }
 }
 
+/// Pick the release asset for `target`, matching on the target triple and a supported archive
+/// suffix. Errors clearly when no asset matches.
+fn select_asset<'a>(
+    release: &'a self_update::update::Release,
+    target: &str,
+) -> Result<&'a self_update::update::ReleaseAsset> {
+    release
+        .assets
+        .iter()
+        .find(|asset| {
+            asset.name.contains(target)
+                && SUPPORTED_ARCHIVE_SUFFIXES
+                    .iter()
+                    .any(|suffix| asset.name.ends_with(suffix))
+        })
+        .ok_or_else(|| {
+            Report::msg(format!(
+                "release {} has no asset for target '{}'",
+                release.version, target
+            ))
+        })
+}
+
+/// Parse the fetched releases under `scheme`, newest-first.
+///
+/// Releases whose tags don't start with `prefix`, or whose prefix-stripped remainder doesn't parse
+/// under the scheme, are skipped. Each entry pairs the parsed version with the original tag (needed
+/// to drive the download).
+fn parse_candidates<'a>(
+    scheme: Scheme,
+    prefix: &str,
+    releases: &'a [self_update::update::Release],
+) -> Vec<(ParsedVersion, &'a str)> {
+    // The self_update crate exposes each release's tag as `version`.
+    let mut candidates: Vec<(ParsedVersion, &str)> = releases
+        .iter()
+        .filter_map(|release| {
+            let stripped = release.version.strip_prefix(prefix)?;
+            let version = ParsedVersion::parse(scheme, stripped)?;
+            Some((version, release.version.as_str()))
+        })
+        .collect();
+
+    // Highest version first, so the first match is the one to pick.
+    candidates.sort_by(|(a, _), (b, _)| b.cmp(a));
+    candidates
+}
+
+/// Select the release matching `download_version` from the fetched list.
+///
+/// Returns the chosen version along with its original tag (needed to drive the download).
+fn select_release(
+    scheme: Scheme,
+    prefix: &str,
+    download_version: &DownloadVersion,
+    lts_major: Option<u64>,
+    releases: &[self_update::update::Release],
+) -> Result<(ParsedVersion, String)> {
+    let candidates = parse_candidates(scheme, prefix, releases);
+
+    let chosen = match download_version {
+        DownloadVersion::Latest => candidates.first(),
+        DownloadVersion::Pinned(req) => candidates.iter().find(|(version, _)| match version {
+            ParsedVersion::Semver(version) => req.matches(version),
+            // Under CalVer a requirement is reinterpreted as a date prefix.
+            ParsedVersion::Calver(fields) => fields.starts_with(&version_req_to_date_prefix(req)),
+        }),
+        DownloadVersion::Channel(Channel::Stable) => {
+            candidates.iter().find(|(version, _)| !version.is_prerelease())
+        }
+        DownloadVersion::Channel(Channel::Nightly) => {
+            candidates.iter().find(|(version, _)| version.is_prerelease())
+        }
+        DownloadVersion::Channel(Channel::Lts) => {
+            let major = match lts_major {
+                Some(major) => major,
+                None => bail!("the lts channel requires 'lts_major' to be set in the config"),
+            };
+            candidates
+                .iter()
+                .find(|(version, _)| !version.is_prerelease() && version.major() == Some(major))
+        }
+    };
+
+    match chosen {
+        Some((version, tag)) => Ok((version.clone(), tag.to_string())),
+        None => bail!("no release matching {} found", download_version),
+    }
+}
+
 /// Configuration for self-update-example. Read from the downstream repository.
 #[serde_as]
 #[derive(Debug, Deserialize)]
@@ -105,6 +317,82 @@ pub struct Config {
     #[serde_as(as = "DisplayFromStr")]
     #[serde(default)]
     version: DownloadVersion,
+
+    /// The major version line the `lts` channel resolves to.
+    #[serde(default)]
+    lts_major: Option<u64>,
+
+    /// How release tags are parsed and ordered.
+    #[serde(default)]
+    scheme: Scheme,
+
+    /// Optional signed-manifest verification of downloaded artifacts.
+    #[serde(default)]
+    verification: Option<Verification>,
+
+    /// Directory to store update history in (default: <workspace root>/.self-update).
+    #[serde(default)]
+    data_dir: Option<Utf8PathBuf>,
+
+    /// Number of previous binaries to retain for rollback.
+    #[serde(default = "default_history_retention")]
+    history_retention: usize,
+
+    /// Target triple override (default: the host triple).
+    #[serde(default)]
+    target: Option<String>,
+}
+
+/// Archive suffixes recognized when matching a release asset to a target.
+static SUPPORTED_ARCHIVE_SUFFIXES: &[&str] =
+    &[".tar.gz", ".tgz", ".tar.xz", ".tar.bz2", ".zip"];
+
+/// Default number of previous binaries retained for rollback.
+fn default_history_retention() -> usize {
+    3
+}
+
+/// Configures signature verification of downloaded artifacts against a signed manifest.
+///
+/// The manifest is a JSON asset attached to each release that maps a target triple to its artifact
+/// filename, the artifact's SHA-256 digest, and a detached Ed25519 signature over that digest. An
+/// update is only applied when the recomputed digest and the signature both check out against the
+/// [`Verification::pubkey`] embedded here.
+#[derive(Debug, Deserialize)]
+pub struct Verification {
+    /// Name of the manifest asset attached to each release.
+    manifest: String,
+
+    /// Ed25519 public key, hex-encoded.
+    pubkey: String,
+}
+
+impl Verification {
+    /// Decode the embedded public key.
+    fn verifying_key(&self) -> Result<ed25519_dalek::VerifyingKey> {
+        let bytes: [u8; ed25519_dalek::PUBLIC_KEY_LENGTH] = hex::decode(&self.pubkey)
+            .wrap_err("error decoding pubkey hex")?
+            .try_into()
+            .map_err(|_| Report::msg("pubkey is not a 32-byte Ed25519 key"))?;
+        ed25519_dalek::VerifyingKey::from_bytes(&bytes).wrap_err("error parsing Ed25519 pubkey")
+    }
+}
+
+/// A signed update manifest: one entry per target triple.
+#[derive(Debug, Deserialize)]
+pub struct Manifest {
+    targets: std::collections::BTreeMap<String, ManifestEntry>,
+}
+
+/// The manifest entry for a single target triple.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ManifestEntry {
+    /// The artifact asset filename.
+    artifact: String,
+    /// The artifact's SHA-256 digest, hex-encoded.
+    sha256: String,
+    /// A detached Ed25519 signature over the raw digest bytes, hex-encoded.
+    signature: String,
 }
 
 impl Config {
@@ -114,6 +402,20 @@ impl Config {
             .with_context(|| format!("error reading config from {}", path))?;
         toml::from_str(&contents).with_context(|| format!("error deserializing config"))
     }
+
+    /// Resolve the directory that retained binaries are saved under for rollback.
+    fn history_dir(&self) -> Result<Utf8PathBuf> {
+        let mut dir = match &self.data_dir {
+            Some(dir) => dir.clone(),
+            None => {
+                let mut root = get_project_root()?;
+                root.push(".self-update");
+                root
+            }
+        };
+        dir.push("history");
+        Ok(dir)
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -127,10 +429,175 @@ pub struct RepoId {
 pub enum DownloadVersion {
     /// Download the latest version.
     Latest,
-    /// Download a version or requirement.
+    /// Download the newest release on a named channel.
+    Channel(Channel),
+    /// Download a version or requirement. Under the CalVer scheme a numeric query like `2024.6` or
+    /// `20240620` is reinterpreted as a date prefix (see [`version_req_to_date_prefix`]).
     Pinned(VersionReq),
 }
 
+/// A named release channel, resolved against the fetched release list rather than matched as a
+/// literal semver requirement.
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub enum Channel {
+    /// The newest release with an empty pre-release segment.
+    Stable,
+    /// The newest release with a non-empty pre-release segment.
+    Nightly,
+    /// The newest stable release on the major line configured as `lts_major`.
+    Lts,
+}
+
+impl Channel {
+    fn from_token(s: &str) -> Option<Self> {
+        if s.eq_ignore_ascii_case("stable") {
+            Some(Channel::Stable)
+        } else if s.eq_ignore_ascii_case("nightly") {
+            Some(Channel::Nightly)
+        } else if s.eq_ignore_ascii_case("lts") {
+            Some(Channel::Lts)
+        } else {
+            None
+        }
+    }
+}
+
+/// The versioning scheme used to parse and order release tags.
+#[derive(Debug, Clone, Copy, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Scheme {
+    /// Standard semantic versions, e.g. `1.2.3` or `1.2.3-rc.1`.
+    #[default]
+    Semver,
+    /// Calendar versions, e.g. `2024.6.0` or the compact `20240620`.
+    Calver,
+}
+
+/// A release tag parsed under a [`Scheme`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ParsedVersion {
+    Semver(Version),
+    /// CalVer decomposed into ordered numeric fields (compact `YYYYMMDD` becomes `[Y, M, D]`).
+    Calver(Vec<u64>),
+}
+
+impl ParsedVersion {
+    /// Parse a prefix-stripped tag under `scheme`, returning `None` if it doesn't fit.
+    fn parse(scheme: Scheme, stripped: &str) -> Option<Self> {
+        match scheme {
+            Scheme::Semver => Version::parse(stripped).ok().map(ParsedVersion::Semver),
+            Scheme::Calver => calver_fields(stripped).map(ParsedVersion::Calver),
+        }
+    }
+
+    /// Whether this version carries a non-empty pre-release segment. CalVer has no notion of
+    /// pre-releases, so it is always considered stable.
+    fn is_prerelease(&self) -> bool {
+        match self {
+            ParsedVersion::Semver(version) => !version.pre.is_empty(),
+            ParsedVersion::Calver(_) => false,
+        }
+    }
+
+    /// The major version line, used to resolve the `lts` channel.
+    fn major(&self) -> Option<u64> {
+        match self {
+            ParsedVersion::Semver(version) => Some(version.major),
+            ParsedVersion::Calver(fields) => fields.first().copied(),
+        }
+    }
+}
+
+impl PartialOrd for ParsedVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ParsedVersion {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (ParsedVersion::Semver(a), ParsedVersion::Semver(b)) => a.cmp(b),
+            (ParsedVersion::Calver(a), ParsedVersion::Calver(b)) => a.cmp(b),
+            // Mixed schemes never coexist in a single release list; order them deterministically.
+            (ParsedVersion::Semver(_), ParsedVersion::Calver(_)) => std::cmp::Ordering::Less,
+            (ParsedVersion::Calver(_), ParsedVersion::Semver(_)) => std::cmp::Ordering::Greater,
+        }
+    }
+}
+
+impl fmt::Display for ParsedVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsedVersion::Semver(version) => write!(f, "{}", version),
+            ParsedVersion::Calver(fields) => {
+                let parts: Vec<String> = fields.iter().map(|part| part.to_string()).collect();
+                f.write_str(&parts.join("."))
+            }
+        }
+    }
+}
+
+/// Decompose a CalVer tag into ordered numeric fields.
+///
+/// Dotted tags like `2024.6.0` parse field-by-field; a compact all-digit `YYYYMMDD` tag is split
+/// into `[year, month, day]` so that it orders lexicographically alongside the dotted form.
+fn calver_fields(s: &str) -> Option<Vec<u64>> {
+    if s.is_empty() {
+        return None;
+    }
+
+    if s.contains('.') {
+        s.split('.').map(|part| part.parse::<u64>().ok()).collect()
+    } else if s.len() == 8 && s.bytes().all(|b| b.is_ascii_digit()) {
+        let year = s[0..4].parse().ok()?;
+        let month = s[4..6].parse().ok()?;
+        let day = s[6..8].parse().ok()?;
+        Some(vec![year, month, day])
+    } else if s.bytes().all(|b| b.is_ascii_digit()) {
+        Some(vec![s.parse().ok()?])
+    } else {
+        None
+    }
+}
+
+/// Derive a date prefix from a semver requirement's leading comparator, so a requirement like
+/// `2024.6` (parsed as `^2024.6`) matches CalVer releases in that year/month.
+fn version_req_to_date_prefix(req: &VersionReq) -> Vec<u64> {
+    match req.comparators.first() {
+        Some(comparator) => {
+            let mut fields = vec![comparator.major];
+            if let Some(minor) = comparator.minor {
+                fields.push(minor);
+                if let Some(patch) = comparator.patch {
+                    fields.push(patch);
+                }
+            }
+            // A bare major may actually be a compact `YYYYMMDD` tag (e.g. `20240620`), which
+            // `calver_fields` decomposes into `[Y, M, D]`; reconcile with that decomposition so a
+            // compact query matches a compact release tag.
+            if fields.len() == 1 {
+                if let Some(decomposed) = calver_fields(&comparator.major.to_string()) {
+                    return decomposed;
+                }
+            }
+            fields
+        }
+        None => Vec::new(),
+    }
+}
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let token = match self {
+            Channel::Stable => "stable",
+            Channel::Nightly => "nightly",
+            Channel::Lts => "lts",
+        };
+        f.write_str(token)
+    }
+}
+
 // serde and structopt use the Default::default impl.
 impl Default for DownloadVersion {
     fn default() -> Self {
@@ -138,6 +605,16 @@ impl Default for DownloadVersion {
     }
 }
 
+impl fmt::Display for DownloadVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DownloadVersion::Latest => write!(f, "latest"),
+            DownloadVersion::Channel(channel) => write!(f, "{}", channel),
+            DownloadVersion::Pinned(req) => write!(f, "{}", req),
+        }
+    }
+}
+
 // This impl is used by structopt to convert a value read from the command-line into a
 // proper value.
 impl FromStr for DownloadVersion {
@@ -146,8 +623,11 @@ impl FromStr for DownloadVersion {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.eq_ignore_ascii_case("latest") {
             Ok(DownloadVersion::Latest)
+        } else if let Some(channel) = Channel::from_token(s) {
+            Ok(DownloadVersion::Channel(channel))
         } else {
-            // Try parsing the version as a semver requirement.
+            // Parse as a semver requirement. Under the CalVer scheme a numeric requirement is
+            // reinterpreted as a date prefix at selection time.
             let version_req = s
                 .parse::<VersionReq>()
                 .with_context(|| format!("error parsing version '{}'", s))?;
@@ -156,6 +636,265 @@ impl FromStr for DownloadVersion {
     }
 }
 
+/// Present an interactive menu of the available releases and return the one the user selects.
+///
+/// Releases are listed newest-first; entries whose tags don't parse under `scheme` are skipped.
+fn prompt_release(
+    scheme: Scheme,
+    prefix: &str,
+    releases: &[self_update::update::Release],
+) -> Result<(ParsedVersion, String)> {
+    let candidates = parse_candidates(scheme, prefix, releases);
+
+    if candidates.is_empty() {
+        bail!("no parseable releases to choose from");
+    }
+
+    let labels: Vec<String> = candidates
+        .iter()
+        .map(|(version, _)| version.to_string())
+        .collect();
+    let selection = dialoguer::Select::new()
+        .with_prompt("Select a release to update to")
+        .items(&labels)
+        .default(0)
+        .interact()
+        .wrap_err("error reading release selection")?;
+
+    let (version, tag) = &candidates[selection];
+    Ok((version.clone(), tag.to_string()))
+}
+
+/// Copy the running binary into the history directory, tagged with `version`, and prune the
+/// directory down to `retention` most-recent entries.
+///
+/// History entries are named `<seq>__<version>`, where `seq` is a zero-padded counter that orders
+/// entries oldest-to-newest. [`rollback`] walks these back one at a time.
+fn save_to_history(history_dir: &Utf8Path, version: &str, retention: usize) -> Result<()> {
+    fs::create_dir_all(history_dir)
+        .with_context(|| format!("error creating history dir {}", history_dir))?;
+
+    let current_exe = current_exe_path()?;
+    let next_seq = history_entries(history_dir)?
+        .last()
+        .map_or(0, |entry| entry.seq + 1);
+
+    let dest = history_dir.join(format!("{:010}__{}", next_seq, version));
+    fs::copy(&current_exe, &dest)
+        .with_context(|| format!("error saving {} to {}", current_exe, dest))?;
+
+    // Retain only the newest `retention` entries.
+    let entries = history_entries(history_dir)?;
+    if entries.len() > retention {
+        for entry in &entries[..entries.len() - retention] {
+            fs::remove_file(&entry.path)
+                .with_context(|| format!("error pruning history entry {}", entry.path))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Restore the most recently saved binary, atomically swapping it into place of the running
+/// executable. The restored entry is consumed so that repeated rollbacks walk further back through
+/// the retained history. Returns the version that was restored.
+fn rollback(history_dir: &Utf8Path) -> Result<String> {
+    let entry = match history_entries(history_dir)?.pop() {
+        Some(entry) => entry,
+        None => bail!("no saved binary to roll back to"),
+    };
+
+    let current_exe = current_exe_path()?;
+    let tmp = current_exe.with_extension("rollback-tmp");
+    // `Move` renames the history entry into place, so it is consumed by the swap; the temp file is
+    // left holding the previously-running binary and must be cleaned up afterwards.
+    self_update::Move::from_source(entry.path.as_std_path())
+        .replace_using_temp(tmp.as_std_path())
+        .to_dest(current_exe.as_std_path())?;
+
+    if tmp.exists() {
+        fs::remove_file(&tmp)
+            .with_context(|| format!("error removing rollback temp file {}", tmp))?;
+    }
+
+    Ok(entry.version)
+}
+
+/// A single saved binary in the history directory.
+struct HistoryEntry {
+    seq: u64,
+    version: String,
+    path: Utf8PathBuf,
+}
+
+/// Read the history directory, returning entries ordered oldest-to-newest by sequence number.
+fn history_entries(history_dir: &Utf8Path) -> Result<Vec<HistoryEntry>> {
+    let mut entries = Vec::new();
+    let read_dir = match fs::read_dir(history_dir) {
+        Ok(read_dir) => read_dir,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(entries),
+        Err(error) => {
+            return Err(error).with_context(|| format!("error reading {}", history_dir))
+        }
+    };
+
+    for dir_entry in read_dir {
+        let path = Utf8PathBuf::try_from(dir_entry?.path())
+            .wrap_err("non-UTF-8 path in history dir")?;
+        if let Some((seq, version)) = path
+            .file_name()
+            .and_then(|name| name.split_once("__"))
+            .and_then(|(seq, version)| Some((seq.parse::<u64>().ok()?, version.to_owned())))
+        {
+            entries.push(HistoryEntry { seq, version, path });
+        }
+    }
+
+    entries.sort_by_key(|entry| entry.seq);
+    Ok(entries)
+}
+
+/// The path to the running executable, as a UTF-8 path.
+fn current_exe_path() -> Result<Utf8PathBuf> {
+    let current_exe = env::current_exe().wrap_err("error locating current executable")?;
+    Utf8PathBuf::try_from(current_exe).wrap_err("current executable path is not UTF-8")
+}
+
+/// Download the signed manifest attached to `release` and return the entry for `target`.
+fn fetch_manifest_entry(
+    verification: &Verification,
+    target: &str,
+    release: &self_update::update::Release,
+) -> Result<ManifestEntry> {
+    // Decode the key eagerly so a misconfigured pubkey fails before the manifest is downloaded.
+    verification.verifying_key()?;
+
+    let manifest_asset = release
+        .assets
+        .iter()
+        .find(|asset| asset.name == verification.manifest)
+        .ok_or_else(|| {
+            Report::msg(format!(
+                "release {} has no manifest asset '{}'",
+                release.version, verification.manifest
+            ))
+        })?;
+    let manifest_bytes = download_asset(&manifest_asset.download_url)
+        .wrap_err("error downloading manifest asset")?;
+    let manifest: Manifest =
+        serde_json::from_slice(&manifest_bytes).wrap_err("error parsing manifest")?;
+
+    manifest
+        .targets
+        .get(target)
+        .cloned()
+        .ok_or_else(|| Report::msg(format!("manifest has no entry for target '{}'", target)))
+}
+
+/// Verify the bytes at `path` against a signed manifest entry.
+///
+/// Recomputes the file's SHA-256 digest, checks it against the manifest entry, and verifies the
+/// detached Ed25519 signature over that digest. Any mismatch is a hard error. Verifying the bytes
+/// on disk — the same bytes that are then installed — is what makes the signature cover the file
+/// actually written, rather than a separately-fetched copy.
+fn verify_file(
+    verification: &Verification,
+    entry: &ManifestEntry,
+    path: &Utf8Path,
+) -> Result<()> {
+    use sha2::{Digest, Sha256};
+
+    let key = verification.verifying_key()?;
+    let bytes = fs::read(path).with_context(|| format!("error reading {}", path))?;
+
+    let digest = Sha256::digest(&bytes);
+    let expected = hex::decode(&entry.sha256).wrap_err("error decoding manifest sha256")?;
+    if digest.as_slice() != expected.as_slice() {
+        bail!("artifact '{}' failed SHA-256 verification", entry.artifact);
+    }
+
+    let signature_bytes: [u8; ed25519_dalek::SIGNATURE_LENGTH] = hex::decode(&entry.signature)
+        .wrap_err("error decoding manifest signature")?
+        .try_into()
+        .map_err(|_| Report::msg("signature is not a 64-byte Ed25519 signature"))?;
+    let signature = ed25519_dalek::Signature::from_bytes(&signature_bytes);
+    key.verify_strict(digest.as_slice(), &signature)
+        .wrap_err_with(|| format!("signature verification failed for '{}'", entry.artifact))?;
+
+    Ok(())
+}
+
+/// Download `asset`, optionally verify the exact downloaded bytes against the signed manifest, then
+/// atomically install the contained `bin_name` binary over the running executable.
+///
+/// Downloading to a local file and installing from it — rather than letting `self_update` re-fetch
+/// the artifact — is what guarantees the verified bytes are the bytes written to disk.
+fn install_update(
+    asset: &self_update::update::ReleaseAsset,
+    bin_name: &str,
+    verify: Option<(&Verification, &ManifestEntry)>,
+    show_progress: bool,
+) -> Result<()> {
+    let tmp_dir = Utf8PathBuf::try_from(env::temp_dir())
+        .wrap_err("temp dir path is not UTF-8")?
+        .join(format!("self-update-{}", std::process::id()));
+    fs::create_dir_all(&tmp_dir)
+        .with_context(|| format!("error creating temp dir {}", tmp_dir))?;
+
+    let archive_path = tmp_dir.join(&asset.name);
+    {
+        let mut file = fs::File::create(&archive_path)
+            .with_context(|| format!("error creating {}", archive_path))?;
+        let mut download = self_update::Download::from_url(&asset.download_url);
+        download.set_header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_static("application/octet-stream"),
+        );
+        download.show_progress(show_progress);
+        download
+            .download_to(&mut file)
+            .wrap_err("error downloading artifact")?;
+    }
+
+    // Refuse to install unless the downloaded file matches the signed manifest.
+    if let Some((verification, entry)) = verify {
+        verify_file(verification, entry, &archive_path)?;
+    }
+
+    // Extract the binary from the downloaded archive and swap it into place atomically.
+    self_update::Extract::from_source(archive_path.as_std_path())
+        .extract_file(tmp_dir.as_std_path(), std::path::Path::new(bin_name))
+        .wrap_err("error extracting update")?;
+    let new_exe = tmp_dir.join(bin_name);
+
+    let current_exe = current_exe_path()?;
+    let swap_tmp = current_exe.with_extension("update-tmp");
+    self_update::Move::from_source(new_exe.as_std_path())
+        .replace_using_temp(swap_tmp.as_std_path())
+        .to_dest(current_exe.as_std_path())?;
+
+    if swap_tmp.exists() {
+        fs::remove_file(&swap_tmp)
+            .with_context(|| format!("error removing update temp file {}", swap_tmp))?;
+    }
+    let _ = fs::remove_dir_all(&tmp_dir);
+
+    Ok(())
+}
+
+/// Download an asset by URL, following the GitHub API's octet-stream convention.
+fn download_asset(url: &str) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut response = reqwest::blocking::Client::new()
+        .get(url)
+        .header(reqwest::header::ACCEPT, "application/octet-stream")
+        .header(reqwest::header::USER_AGENT, "self-update-example")
+        .send()?
+        .error_for_status()?;
+    response.copy_to(&mut bytes)?;
+    Ok(bytes)
+}
+
 fn get_project_root() -> Result<Utf8PathBuf> {
     color_eyre::install()?;
 